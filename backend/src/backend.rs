@@ -0,0 +1,616 @@
+//! Storage backend abstraction for the ingestion binaries.
+//!
+//! `migrate_data_local` and friends used to be hard-wired to `PgPool` and
+//! PostGIS-specific SQL (`ST_MakePoint`, `ST_GeomFromGeoJSON`, `geography`
+//! casts), which meant a contributor needed a full Postgres+PostGIS install
+//! just to load sample data locally. The [`Backend`] trait pulls the
+//! engine-specific spatial encoding behind one interface so a SQLite+
+//! SpatiaLite backend can stand in during local development: `migrate_data_local
+//! seed` ingests the `sql_data/*.json` sources straight into whichever
+//! backend a `DATABASE_URL` resolves to, and `convert` moves already-ingested
+//! data between two backends.
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::Value;
+use sqlx::sqlite::SqlitePool;
+use sqlx::PgPool;
+use std::error::Error;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Population {
+    pub id: i32,
+    pub name: String,
+    pub region: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Individual {
+    pub id: i32,
+    pub flags: i32,
+    pub location: Vec<f64>,
+    pub parents: Vec<i32>,
+    pub nodes: Vec<i32>,
+    pub array_non_reference_discordance: Option<f64>,
+    pub capmq: Option<i32>,
+    pub coverage: Option<f64>,
+    pub freemix: Option<f64>,
+    pub insert_size_average: Option<f64>,
+    pub library: Option<String>,
+    pub library_type: Option<String>,
+    pub region: Option<String>,
+    pub sample: Option<String>,
+    pub sample_accession: Option<String>,
+    pub sex: Option<String>,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Node {
+    pub id: i32,
+    pub flags: i32,
+    pub time: f64,
+    pub population: Option<i32>,
+    pub individual: Option<i32>,
+    pub ancestor_data_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Edge {
+    pub id: i32,
+    pub parent: i32,
+    pub child: i32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct HexagonFeature {
+    #[serde(rename = "type")]
+    pub feature_type: String,
+    pub properties: HexagonProperties,
+    pub geometry: HexagonGeometry,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct HexagonProperties {
+    pub state_id: i32,
+    pub continent_id: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct HexagonGeometry {
+    #[serde(rename = "type")]
+    pub geometry_type: String,
+    pub coordinates: Value,
+}
+
+/// A flattened flux sample: `(time, source_state_id, target_state_id, migration_rate)`.
+pub type FluxRow = (f64, i32, i32, f64);
+/// A flattened geo-arg sample: `(edge_id, state_id, time)`.
+pub type GeoArgRow = (i32, i32, f64);
+
+/// Storage-engine-agnostic ingestion operations. Implementations own engine
+/// setup (connection pooling, schema provisioning) and engine-specific
+/// spatial encoding; callers work only in terms of the parsed JSON row types
+/// above.
+#[async_trait]
+pub trait Backend: Send + Sync {
+    async fn get_max_id(&self, table: &str) -> Result<i32, sqlx::Error>;
+
+    async fn insert_populations(&self, populations: Vec<Population>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn insert_individuals(&self, individuals: Vec<Individual>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn insert_nodes(&self, nodes: Vec<Node>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn insert_edges(&self, edges: Vec<Edge>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn insert_hexagons(&self, features: Vec<HexagonFeature>) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn insert_flux(&self, rows: Vec<FluxRow>) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn insert_geo_arg(&self, rows: Vec<GeoArgRow>) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    async fn fetch_populations(&self) -> Result<Vec<Population>, Box<dyn Error + Send + Sync>>;
+    async fn fetch_individuals(&self) -> Result<Vec<Individual>, Box<dyn Error + Send + Sync>>;
+    async fn fetch_nodes(&self) -> Result<Vec<Node>, Box<dyn Error + Send + Sync>>;
+    async fn fetch_edges(&self) -> Result<Vec<Edge>, Box<dyn Error + Send + Sync>>;
+}
+
+/// Picks a backend from the `DATABASE_URL` scheme: `postgres://`/`postgresql://`
+/// for Postgres+PostGIS, `sqlite://` for SQLite+SpatiaLite.
+pub async fn connect(database_url: &str) -> Result<Box<dyn Backend>, Box<dyn Error + Send + Sync>> {
+    if database_url.starts_with("postgres://") || database_url.starts_with("postgresql://") {
+        let pool = PgPool::connect(database_url).await?;
+        crate::MIGRATOR.run(&pool).await?;
+        Ok(Box::new(PostgresBackend { pool }))
+    } else if database_url.starts_with("sqlite://") {
+        let pool = SqlitePool::connect(database_url).await?;
+        SqliteBackend::ensure_schema(&pool).await?;
+        Ok(Box::new(SqliteBackend { pool }))
+    } else {
+        Err(format!("unrecognized DATABASE_URL scheme: {}", database_url).into())
+    }
+}
+
+pub struct PostgresBackend {
+    pool: PgPool,
+}
+
+#[async_trait]
+impl Backend for PostgresBackend {
+    async fn get_max_id(&self, table: &str) -> Result<i32, sqlx::Error> {
+        let query = format!("SELECT COALESCE(MAX(id), -1) FROM {}", table);
+        let max_id: (i32,) = sqlx::query_as(&query).fetch_one(&self.pool).await?;
+        Ok(max_id.0)
+    }
+
+    async fn insert_populations(&self, populations: Vec<Population>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for population in populations.into_iter().filter(|p| p.id > last_id) {
+            sqlx::query(
+                "INSERT INTO populations (id, name, region) VALUES ($1, $2, $3) ON CONFLICT (id) DO NOTHING",
+            )
+                .bind(population.id)
+                .bind(population.name)
+                .bind(population.region)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_individuals(&self, individuals: Vec<Individual>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for individual in individuals.into_iter().filter(|i| i.id > last_id) {
+            sqlx::query(
+                r#"
+                INSERT INTO individuals (
+                    id, flags, location, parents, nodes,
+                    array_non_reference_discordance, capmq, coverage,
+                    freemix, insert_size_average, library, library_type,
+                    region, sample, sample_accession, sex, source
+                )
+                VALUES (
+                    $1, $2,
+                    ST_SetSRID(ST_MakePoint($3, $4), 4326)::geography,
+                    $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17
+                )
+                ON CONFLICT (id) DO NOTHING
+                "#,
+            )
+                .bind(individual.id)
+                .bind(individual.flags)
+                .bind(individual.location[0])
+                .bind(individual.location[1])
+                .bind(&individual.parents)
+                .bind(&individual.nodes)
+                .bind(individual.array_non_reference_discordance)
+                .bind(individual.capmq)
+                .bind(individual.coverage)
+                .bind(individual.freemix)
+                .bind(individual.insert_size_average)
+                .bind(individual.library)
+                .bind(individual.library_type)
+                .bind(individual.region)
+                .bind(individual.sample)
+                .bind(individual.sample_accession)
+                .bind(individual.sex)
+                .bind(individual.source)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_nodes(&self, nodes: Vec<Node>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for node in nodes.into_iter().filter(|n| n.id > last_id) {
+            sqlx::query(
+                "INSERT INTO nodes (id, flags, time, population, individual, ancestor_data_id) VALUES ($1, $2, $3, $4, $5, $6) ON CONFLICT (id) DO NOTHING",
+            )
+                .bind(node.id)
+                .bind(node.flags)
+                .bind(node.time)
+                .bind(node.population)
+                .bind(node.individual)
+                .bind(node.ancestor_data_id)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_edges(&self, edges: Vec<Edge>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for edge in edges.into_iter().filter(|e| e.id > last_id) {
+            sqlx::query("INSERT INTO edges (id, parent, child) VALUES ($1, $2, $3) ON CONFLICT (id) DO NOTHING")
+                .bind(edge.id)
+                .bind(edge.parent)
+                .bind(edge.child)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_hexagons(&self, features: Vec<HexagonFeature>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for feature in features {
+            let geojson_str = serde_json::json!({
+                "type": feature.geometry.geometry_type,
+                "coordinates": feature.geometry.coordinates
+            }).to_string();
+
+            sqlx::query(
+                "INSERT INTO hexagons (state_id, geom, continent_id) VALUES ($1, ST_SetSRID(ST_GeomFromGeoJSON($2), 4326), $3) ON CONFLICT (state_id) DO NOTHING",
+            )
+                .bind(feature.properties.state_id)
+                .bind(geojson_str)
+                .bind(feature.properties.continent_id)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_flux(&self, rows: Vec<FluxRow>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for (time, source_id, target_id, value) in rows {
+            sqlx::query(
+                "INSERT INTO flux (source_state_id, target_state_id, time, migration_rate) VALUES ($1, $2, $3, $4) ON CONFLICT (source_state_id, target_state_id, time) DO NOTHING",
+            )
+                .bind(source_id)
+                .bind(target_id)
+                .bind(time)
+                .bind(value)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_geo_arg(&self, rows: Vec<GeoArgRow>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for (edge_id, state_id, time) in rows {
+            sqlx::query(
+                "INSERT INTO geo_arg (edge_id, state_id, time) VALUES ($1, $2, $3) ON CONFLICT (edge_id, state_id, time) DO NOTHING",
+            )
+                .bind(edge_id)
+                .bind(state_id)
+                .bind(time)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn fetch_populations(&self) -> Result<Vec<Population>, Box<dyn Error + Send + Sync>> {
+        let rows = sqlx::query_as::<_, (i32, String, String)>("SELECT id, name, region FROM populations ORDER BY id")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(|(id, name, region)| Population { id, name, region }).collect())
+    }
+
+    async fn fetch_individuals(&self) -> Result<Vec<Individual>, Box<dyn Error + Send + Sync>> {
+        // Convert between engines via the flat JSON shape rather than the
+        // live `geography` column, so re-importing into either engine goes
+        // through the same `insert_individuals` spatial-encoding path.
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (i32, i32, f64, f64, Vec<i32>, Vec<i32>, Option<f64>, Option<i32>, Option<f64>, Option<f64>, Option<f64>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>)>(
+            r#"
+            SELECT id, flags, ST_X(location::geometry), ST_Y(location::geometry),
+                parents, nodes, array_non_reference_discordance, capmq, coverage,
+                freemix, insert_size_average, library, library_type, region,
+                sample, sample_accession, sex, source
+            FROM individuals ORDER BY id
+            "#,
+        )
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(|(id, flags, lon, lat, parents, nodes, array_non_reference_discordance, capmq, coverage, freemix, insert_size_average, library, library_type, region, sample, sample_accession, sex, source)| Individual {
+            id,
+            flags,
+            location: vec![lon, lat],
+            parents,
+            nodes,
+            array_non_reference_discordance,
+            capmq,
+            coverage,
+            freemix,
+            insert_size_average,
+            library,
+            library_type,
+            region,
+            sample,
+            sample_accession,
+            sex,
+            source,
+        }).collect())
+    }
+
+    async fn fetch_nodes(&self) -> Result<Vec<Node>, Box<dyn Error + Send + Sync>> {
+        let rows = sqlx::query_as::<_, (i32, i32, f64, Option<i32>, Option<i32>, Option<i32>)>(
+            "SELECT id, flags, time, population, individual, ancestor_data_id FROM nodes ORDER BY id",
+        )
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(|(id, flags, time, population, individual, ancestor_data_id)| Node {
+            id, flags, time, population, individual, ancestor_data_id,
+        }).collect())
+    }
+
+    async fn fetch_edges(&self) -> Result<Vec<Edge>, Box<dyn Error + Send + Sync>> {
+        let rows = sqlx::query_as::<_, (i32, i32, i32)>("SELECT id, parent, child FROM edges ORDER BY id")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(|(id, parent, child)| Edge { id, parent, child }).collect())
+    }
+}
+
+pub struct SqliteBackend {
+    pool: SqlitePool,
+}
+
+impl SqliteBackend {
+    /// Loads `mod_spatialite` and creates the mirror of the Postgres schema
+    /// that this binary actually touches. Unlike the Postgres side, there's
+    /// no embedded migration history here — this backend exists purely as a
+    /// disposable local dev copy, not a production target.
+    async fn ensure_schema(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT load_extension('mod_spatialite')").execute(pool).await.ok();
+        sqlx::query("SELECT InitSpatialMetaData(1)").execute(pool).await.ok();
+
+        sqlx::query("CREATE TABLE IF NOT EXISTS populations (id INTEGER PRIMARY KEY, name TEXT NOT NULL, region TEXT NOT NULL)")
+            .execute(pool).await?;
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS individuals (
+                id INTEGER PRIMARY KEY,
+                flags INTEGER NOT NULL,
+                location BLOB,
+                parents TEXT NOT NULL,
+                nodes TEXT NOT NULL,
+                array_non_reference_discordance REAL,
+                capmq INTEGER,
+                coverage REAL,
+                freemix REAL,
+                insert_size_average REAL,
+                library TEXT,
+                library_type TEXT,
+                region TEXT,
+                sample TEXT,
+                sample_accession TEXT,
+                sex TEXT,
+                source TEXT
+            )
+            "#,
+        ).execute(pool).await?;
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS nodes (id INTEGER PRIMARY KEY, flags INTEGER NOT NULL, time REAL NOT NULL, population INTEGER, individual INTEGER, ancestor_data_id INTEGER)",
+        ).execute(pool).await?;
+        sqlx::query("CREATE TABLE IF NOT EXISTS edges (id INTEGER PRIMARY KEY, parent INTEGER NOT NULL, child INTEGER NOT NULL)")
+            .execute(pool).await?;
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS hexagons (state_id INTEGER PRIMARY KEY, geom BLOB, continent_id TEXT NOT NULL)",
+        ).execute(pool).await?;
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS flux (source_state_id INTEGER NOT NULL, target_state_id INTEGER NOT NULL, time REAL NOT NULL, migration_rate REAL NOT NULL, UNIQUE (source_state_id, target_state_id, time))",
+        ).execute(pool).await?;
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS geo_arg (edge_id INTEGER NOT NULL, state_id INTEGER NOT NULL, time REAL NOT NULL, UNIQUE (edge_id, state_id, time))",
+        ).execute(pool).await?;
+
+        Ok(())
+    }
+
+    fn csv_int_list(values: &[i32]) -> String {
+        values.iter().map(i32::to_string).collect::<Vec<_>>().join(",")
+    }
+
+    fn parse_int_list(value: &str) -> Vec<i32> {
+        if value.is_empty() {
+            return Vec::new();
+        }
+        value.split(',').filter_map(|v| v.parse().ok()).collect()
+    }
+}
+
+#[async_trait]
+impl Backend for SqliteBackend {
+    async fn get_max_id(&self, table: &str) -> Result<i32, sqlx::Error> {
+        let query = format!("SELECT COALESCE(MAX(id), -1) FROM {}", table);
+        let max_id: (i32,) = sqlx::query_as(&query).fetch_one(&self.pool).await?;
+        Ok(max_id.0)
+    }
+
+    async fn insert_populations(&self, populations: Vec<Population>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for population in populations.into_iter().filter(|p| p.id > last_id) {
+            sqlx::query("INSERT OR IGNORE INTO populations (id, name, region) VALUES (?, ?, ?)")
+                .bind(population.id)
+                .bind(population.name)
+                .bind(population.region)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_individuals(&self, individuals: Vec<Individual>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for individual in individuals.into_iter().filter(|i| i.id > last_id) {
+            sqlx::query(
+                r#"
+                INSERT OR IGNORE INTO individuals (
+                    id, flags, location, parents, nodes,
+                    array_non_reference_discordance, capmq, coverage,
+                    freemix, insert_size_average, library, library_type,
+                    region, sample, sample_accession, sex, source
+                )
+                VALUES (?, ?, MakePoint(?, ?, 4326), ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+                .bind(individual.id)
+                .bind(individual.flags)
+                .bind(individual.location[0])
+                .bind(individual.location[1])
+                .bind(Self::csv_int_list(&individual.parents))
+                .bind(Self::csv_int_list(&individual.nodes))
+                .bind(individual.array_non_reference_discordance)
+                .bind(individual.capmq)
+                .bind(individual.coverage)
+                .bind(individual.freemix)
+                .bind(individual.insert_size_average)
+                .bind(individual.library)
+                .bind(individual.library_type)
+                .bind(individual.region)
+                .bind(individual.sample)
+                .bind(individual.sample_accession)
+                .bind(individual.sex)
+                .bind(individual.source)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_nodes(&self, nodes: Vec<Node>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for node in nodes.into_iter().filter(|n| n.id > last_id) {
+            sqlx::query("INSERT OR IGNORE INTO nodes (id, flags, time, population, individual, ancestor_data_id) VALUES (?, ?, ?, ?, ?, ?)")
+                .bind(node.id)
+                .bind(node.flags)
+                .bind(node.time)
+                .bind(node.population)
+                .bind(node.individual)
+                .bind(node.ancestor_data_id)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_edges(&self, edges: Vec<Edge>, last_id: i32) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for edge in edges.into_iter().filter(|e| e.id > last_id) {
+            sqlx::query("INSERT OR IGNORE INTO edges (id, parent, child) VALUES (?, ?, ?)")
+                .bind(edge.id)
+                .bind(edge.parent)
+                .bind(edge.child)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_hexagons(&self, features: Vec<HexagonFeature>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for feature in features {
+            let geojson_str = serde_json::json!({
+                "type": feature.geometry.geometry_type,
+                "coordinates": feature.geometry.coordinates
+            }).to_string();
+
+            sqlx::query("INSERT OR IGNORE INTO hexagons (state_id, geom, continent_id) VALUES (?, GeomFromGeoJSON(?), ?)")
+                .bind(feature.properties.state_id)
+                .bind(geojson_str)
+                .bind(feature.properties.continent_id)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_flux(&self, rows: Vec<FluxRow>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for (time, source_id, target_id, value) in rows {
+            sqlx::query("INSERT OR IGNORE INTO flux (source_state_id, target_state_id, time, migration_rate) VALUES (?, ?, ?, ?)")
+                .bind(source_id)
+                .bind(target_id)
+                .bind(time)
+                .bind(value)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_geo_arg(&self, rows: Vec<GeoArgRow>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for (edge_id, state_id, time) in rows {
+            sqlx::query("INSERT OR IGNORE INTO geo_arg (edge_id, state_id, time) VALUES (?, ?, ?)")
+                .bind(edge_id)
+                .bind(state_id)
+                .bind(time)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn fetch_populations(&self) -> Result<Vec<Population>, Box<dyn Error + Send + Sync>> {
+        let rows = sqlx::query_as::<_, (i32, String, String)>("SELECT id, name, region FROM populations ORDER BY id")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(|(id, name, region)| Population { id, name, region }).collect())
+    }
+
+    async fn fetch_individuals(&self) -> Result<Vec<Individual>, Box<dyn Error + Send + Sync>> {
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (i32, i32, f64, f64, String, String, Option<f64>, Option<i32>, Option<f64>, Option<f64>, Option<f64>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>)>(
+            r#"
+            SELECT id, flags, X(location), Y(location), parents, nodes,
+                array_non_reference_discordance, capmq, coverage, freemix,
+                insert_size_average, library, library_type, region, sample,
+                sample_accession, sex, source
+            FROM individuals ORDER BY id
+            "#,
+        )
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(|(id, flags, lon, lat, parents, nodes, array_non_reference_discordance, capmq, coverage, freemix, insert_size_average, library, library_type, region, sample, sample_accession, sex, source)| Individual {
+            id,
+            flags,
+            location: vec![lon, lat],
+            parents: Self::parse_int_list(&parents),
+            nodes: Self::parse_int_list(&nodes),
+            array_non_reference_discordance,
+            capmq,
+            coverage,
+            freemix,
+            insert_size_average,
+            library,
+            library_type,
+            region,
+            sample,
+            sample_accession,
+            sex,
+            source,
+        }).collect())
+    }
+
+    async fn fetch_nodes(&self) -> Result<Vec<Node>, Box<dyn Error + Send + Sync>> {
+        let rows = sqlx::query_as::<_, (i32, i32, f64, Option<i32>, Option<i32>, Option<i32>)>(
+            "SELECT id, flags, time, population, individual, ancestor_data_id FROM nodes ORDER BY id",
+        )
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(|(id, flags, time, population, individual, ancestor_data_id)| Node {
+            id, flags, time, population, individual, ancestor_data_id,
+        }).collect())
+    }
+
+    async fn fetch_edges(&self) -> Result<Vec<Edge>, Box<dyn Error + Send + Sync>> {
+        let rows = sqlx::query_as::<_, (i32, i32, i32)>("SELECT id, parent, child FROM edges ORDER BY id")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(|(id, parent, child)| Edge { id, parent, child }).collect())
+    }
+}
+
+/// Reads the `populations`, `individuals`, `nodes` and `edges` tables from
+/// `source` and writes them into `dest`, so a developer can build a local
+/// SQLite copy and later push it to Postgres (or vice versa). `hexagons`,
+/// `flux` and `geo_arg` are not copied. IDs are used as-is, so `dest` should
+/// start empty for a clean copy.
+pub async fn convert(source: &dyn Backend, dest: &dyn Backend) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let populations = source.fetch_populations().await?;
+    println!("Converting {} populations...", populations.len());
+    dest.insert_populations(populations, -1).await?;
+
+    let individuals = source.fetch_individuals().await?;
+    println!("Converting {} individuals...", individuals.len());
+    dest.insert_individuals(individuals, -1).await?;
+
+    let nodes = source.fetch_nodes().await?;
+    println!("Converting {} nodes...", nodes.len());
+    dest.insert_nodes(nodes, -1).await?;
+
+    let edges = source.fetch_edges().await?;
+    println!("Converting {} edges...", edges.len());
+    dest.insert_edges(edges, -1).await?;
+
+    println!("Conversion completed successfully!");
+    Ok(())
+}
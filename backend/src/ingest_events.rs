@@ -0,0 +1,33 @@
+//! Live-update channel for the legacy ingestion pipeline. Polling for new
+//! `flux_entries`/`georef_entries`/`points` rows means the dashboard is
+//! always at least one poll interval stale; this publishes a `NOTIFY` after
+//! each batch commits so that staleness is at least visible on `CHANNEL`,
+//! via `psql`'s own `LISTEN` or any ad hoc `PgListener`. Nothing in this
+//! repo subscribes yet — there's no websocket/SSE route forwarding these to
+//! a dashboard client, so treat this as publish-only until one exists.
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::error::Error;
+
+pub const CHANNEL: &str = "gaia_ingest";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "table", rename_all = "snake_case")]
+pub enum IngestEvent {
+    FluxEntries { time_index: Option<i32>, count: usize },
+    GeorefEntries { count: usize },
+    Points { count: usize },
+}
+
+/// Publishes `event` on [`CHANNEL`] via `pg_notify`, which (unlike a literal
+/// `NOTIFY channel, '...'` string) lets the payload go through as a bound
+/// parameter instead of being spliced into SQL text.
+pub async fn publish(pool: &PgPool, event: &IngestEvent) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let payload = serde_json::to_string(event)?;
+    sqlx::query("SELECT pg_notify($1, $2)")
+        .bind(CHANNEL)
+        .bind(payload)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,15 @@
+// Embedded, versioned schema migrations (see `migrations/`). This is the
+// single source of truth for the individuals/nodes/edges/hexagons/flux/geo_arg
+// schema, shared by the server binary and the ingestion binaries so neither
+// has to assume the schema already exists.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+// Embedded, versioned, *reversible* migrations for the separate legacy
+// hexagon_cells/points/flux_entries/georef_entries schema that `migrate_data`
+// ingests into. Kept in its own directory and its own `Migrator` instance so
+// a bad deploy here can be rolled back with `.undo()` without touching the
+// unrelated individuals/nodes/edges schema above.
+pub static LEGACY_MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations_legacy");
+
+pub mod backend;
+pub mod ingest_events;
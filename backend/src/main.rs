@@ -1,19 +1,114 @@
 use axum::{
-    routing::get,
+    routing::{get, post},
     Router,
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
 use serde::{Deserialize, Serialize};
 use sqlx::postgres::PgPoolOptions;
 use tower_http::cors::CorsLayer;
+use tower_http::compression::{CompressionLayer, CompressionLevel};
 use std::sync::Arc;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
 use sqlx::types::JsonValue;
+use rstar::{RTree, RTreeObject, PointDistance, AABB};
+use tokio::sync::RwLock;
+use thiserror::Error;
+use gaia_dashboard::MIGRATOR;
+
+// Application-wide error type. Every handler returns `Result<_, AppError>`
+// so a database outage or malformed request produces a real status code and
+// JSON error body instead of a silently empty `200 OK`.
+#[derive(Error, Debug)]
+enum AppError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("not found")]
+    NotFound,
+    #[error("bad request: {0}")]
+    BadRequest(String),
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let (status, detail) = match &self {
+            // The raw sqlx error can quote SQL/constraint/column names (and,
+            // for connection failures, DSN-adjacent details) — log it
+            // server-side and hand the client a fixed string so a 500 still
+            // tells the frontend "backend is broken" without leaking
+            // internals.
+            AppError::Database(err) => {
+                tracing::error!("database error: {}", err);
+                (StatusCode::INTERNAL_SERVER_ERROR, "internal server error".to_string())
+            }
+            AppError::NotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            AppError::BadRequest(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+        };
+
+        (
+            status,
+            Json(serde_json::json!({ "error": status.canonical_reason().unwrap_or("error"), "detail": detail })),
+        )
+            .into_response()
+    }
+}
+
+// A pre-serialized JSON response body plus the time it was produced, used to
+// avoid re-querying and re-serializing rarely-changing full-table endpoints
+// on every request.
+#[derive(Clone)]
+struct CachedPayload {
+    body: Vec<u8>,
+    cached_at: std::time::Instant,
+}
 
 // Shared application state
 #[derive(Clone)]
 struct AppState {
     pool: sqlx::PgPool,
+    flux_graph: Arc<RwLock<FluxGraph>>,
+    hex_index: Arc<RwLock<RTree<HexCenter>>>,
+    cache_ttl: std::time::Duration,
+    hexagon_cache: Arc<RwLock<Option<CachedPayload>>>,
+    population_cache: Arc<RwLock<Option<CachedPayload>>>,
+    average_flux_cache: Arc<RwLock<Option<CachedPayload>>>,
+}
+
+// Returns the cached body if it's still within `ttl`, otherwise calls
+// `refresh` under a write lock and caches the result. The write lock is
+// re-checked after acquisition so concurrent requests racing past a stale
+// cache don't all pay for the refresh.
+async fn get_or_refresh_cached<F, Fut>(
+    cache: &Arc<RwLock<Option<CachedPayload>>>,
+    ttl: std::time::Duration,
+    refresh: F,
+) -> Result<Vec<u8>, AppError>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<Vec<u8>, AppError>>,
+{
+    if let Some(cached) = cache.read().await.as_ref() {
+        if cached.cached_at.elapsed() < ttl {
+            return Ok(cached.body.clone());
+        }
+    }
+
+    let mut guard = cache.write().await;
+    if let Some(cached) = guard.as_ref() {
+        if cached.cached_at.elapsed() < ttl {
+            return Ok(cached.body.clone());
+        }
+    }
+
+    let body = refresh().await?;
+    *guard = Some(CachedPayload {
+        body: body.clone(),
+        cached_at: std::time::Instant::now(),
+    });
+    Ok(body)
 }
 
 // Data structures for each table
@@ -83,10 +178,224 @@ struct AverageFlux {
     average_migration_rate: f64,
 }
 
+// In-memory adjacency list over the averaged flux graph. Each edge's cost is
+// -ln(average_migration_rate) so that summing costs along a path is
+// equivalent to multiplying the underlying probabilities, which lets plain
+// Dijkstra find the highest-probability corridor instead of the shortest hop
+// count.
+#[derive(Default, Clone)]
+struct FluxGraph {
+    adjacency: HashMap<i32, Vec<(i32, f64, f64)>>, // target_state_id, rate, cost
+}
+
+#[derive(Serialize)]
+struct MigrationRoute {
+    path: Vec<i32>,
+    hop_rates: Vec<f64>,
+    total_probability: f64,
+    reachable: bool,
+}
+
+struct HeapEntry {
+    cost: f64,
+    state_id: i32,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+impl Eq for HeapEntry {}
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so BinaryHeap (a max-heap) pops the lowest cost first.
+        other.cost.partial_cmp(&self.cost).unwrap_or(Ordering::Equal)
+    }
+}
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FluxGraph {
+    fn shortest_path(&self, source_state_id: i32, target_state_id: i32) -> MigrationRoute {
+        let mut costs: HashMap<i32, f64> = HashMap::new();
+        let mut predecessors: HashMap<i32, (i32, f64)> = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        costs.insert(source_state_id, 0.0);
+        heap.push(HeapEntry { cost: 0.0, state_id: source_state_id });
+
+        while let Some(HeapEntry { cost, state_id }) = heap.pop() {
+            if state_id == target_state_id {
+                break;
+            }
+            if cost > *costs.get(&state_id).unwrap_or(&f64::INFINITY) {
+                continue;
+            }
+
+            if let Some(edges) = self.adjacency.get(&state_id) {
+                for &(neighbor, rate, edge_cost) in edges {
+                    let next_cost = cost + edge_cost;
+                    if next_cost < *costs.get(&neighbor).unwrap_or(&f64::INFINITY) {
+                        costs.insert(neighbor, next_cost);
+                        predecessors.insert(neighbor, (state_id, rate));
+                        heap.push(HeapEntry { cost: next_cost, state_id: neighbor });
+                    }
+                }
+            }
+        }
+
+        if !costs.contains_key(&target_state_id) && source_state_id != target_state_id {
+            return MigrationRoute {
+                path: Vec::new(),
+                hop_rates: Vec::new(),
+                total_probability: 0.0,
+                reachable: false,
+            };
+        }
+
+        let mut path = vec![target_state_id];
+        let mut hop_rates = Vec::new();
+        let mut current = target_state_id;
+        while let Some(&(prev, rate)) = predecessors.get(&current) {
+            path.push(prev);
+            hop_rates.push(rate);
+            current = prev;
+        }
+        path.reverse();
+        hop_rates.reverse();
+
+        let total_probability = hop_rates.iter().product::<f64>().max(if source_state_id == target_state_id { 1.0 } else { 0.0 });
+
+        MigrationRoute {
+            path,
+            hop_rates,
+            total_probability,
+            reachable: true,
+        }
+    }
+}
+
+async fn load_flux_graph(pool: &sqlx::PgPool) -> Result<FluxGraph, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            source_state_id,
+            target_state_id,
+            AVG(migration_rate) as "average_migration_rate!: f64"
+        FROM flux
+        GROUP BY source_state_id, target_state_id
+        HAVING AVG(migration_rate) > 0
+        "#
+    )
+        .fetch_all(pool)
+        .await?;
+
+    let mut adjacency: HashMap<i32, Vec<(i32, f64, f64)>> = HashMap::new();
+    for row in rows {
+        let cost = -row.average_migration_rate.ln();
+        adjacency
+            .entry(row.source_state_id)
+            .or_default()
+            .push((row.target_state_id, row.average_migration_rate, cost));
+    }
+
+    Ok(FluxGraph { adjacency })
+}
+
+// Point wrapper so hexagon centers can be indexed in an R-tree and snapped
+// to from arbitrary lon/lat inputs.
+#[derive(Clone, Copy, Debug)]
+struct HexCenter {
+    state_id: i32,
+    lon: f64,
+    lat: f64,
+}
+
+impl RTreeObject for HexCenter {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_point([self.lon, self.lat])
+    }
+}
+
+impl PointDistance for HexCenter {
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        let dx = self.lon - point[0];
+        let dy = self.lat - point[1];
+        dx * dx + dy * dy
+    }
+}
+
+async fn load_hex_index(pool: &sqlx::PgPool) -> Result<RTree<HexCenter>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT state_id, center_lon, center_lat
+        FROM hexagons
+        WHERE center_lon IS NOT NULL AND center_lat IS NOT NULL
+        "#
+    )
+        .fetch_all(pool)
+        .await?;
+
+    let points: Vec<HexCenter> = rows
+        .into_iter()
+        .map(|row| HexCenter {
+            state_id: row.state_id,
+            lon: row.center_lon.unwrap(),
+            lat: row.center_lat.unwrap(),
+        })
+        .collect();
+
+    Ok(RTree::bulk_load(points))
+}
+
+#[derive(Deserialize)]
+struct MigrationRouteQuery {
+    source_lon: Option<f64>,
+    source_lat: Option<f64>,
+    target_lon: Option<f64>,
+    target_lat: Option<f64>,
+}
+
+async fn get_migration_route(
+    Path((source_state_id, target_state_id)): Path<(i32, i32)>,
+    Query(query): Query<MigrationRouteQuery>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<MigrationRoute>, AppError> {
+    let source_state_id = match (query.source_lon, query.source_lat) {
+        (Some(lon), Some(lat)) => state
+            .hex_index
+            .read()
+            .await
+            .nearest_neighbor(&[lon, lat])
+            .map(|point| point.state_id)
+            .unwrap_or(source_state_id),
+        _ => source_state_id,
+    };
+    let target_state_id = match (query.target_lon, query.target_lat) {
+        (Some(lon), Some(lat)) => state
+            .hex_index
+            .read()
+            .await
+            .nearest_neighbor(&[lon, lat])
+            .map(|point| point.state_id)
+            .unwrap_or(target_state_id),
+        _ => target_state_id,
+    };
+
+    let graph = state.flux_graph.read().await;
+    Ok(Json(graph.shortest_path(source_state_id, target_state_id)))
+}
+
 // Handler functions for each endpoint
 async fn get_all_individuals(
     State(state): State<Arc<AppState>>,
-) -> Json<Vec<Individual>> {
+) -> Result<Json<Vec<Individual>>, AppError> {
     let individuals = sqlx::query_as!(
         Individual,
         r#"
@@ -112,54 +421,63 @@ async fn get_all_individuals(
         "#
     )
         .fetch_all(&state.pool)
-        .await
-        .unwrap_or_default();
+        .await?;
 
-    Json(individuals)
+    Ok(Json(individuals))
 }
 
 async fn get_all_hexagons(
     State(state): State<Arc<AppState>>,
-) -> Json<Vec<Hexagon>> {
-    let hexagons = sqlx::query_as!(
-        Hexagon,
-        r#"
-        SELECT
-            state_id,
-            ST_AsGeoJSON(geom)::jsonb as "geom!: JsonValue",
-            continent_id,
-            center_lon,
-            center_lat
-        FROM hexagons
-        "#
-    )
-        .fetch_all(&state.pool)
-        .await
-        .unwrap_or_default();
+) -> Result<impl IntoResponse, AppError> {
+    let pool = state.pool.clone();
+    let body = get_or_refresh_cached(&state.hexagon_cache, state.cache_ttl, || async move {
+        let hexagons = sqlx::query_as!(
+            Hexagon,
+            r#"
+            SELECT
+                state_id,
+                ST_AsGeoJSON(geom)::jsonb as "geom!: JsonValue",
+                continent_id,
+                center_lon,
+                center_lat
+            FROM hexagons
+            "#
+        )
+            .fetch_all(&pool)
+            .await?;
+
+        Ok(serde_json::to_vec(&hexagons).unwrap_or_default())
+    })
+        .await?;
 
-    Json(hexagons)
+    Ok(([(header::CONTENT_TYPE, "application/json")], body))
 }
 
 async fn get_all_populations(
     State(state): State<Arc<AppState>>,
-) -> Json<Vec<Population>> {
-    let populations = sqlx::query_as!(
-        Population,
-        r#"
-        SELECT id, name, region
-        FROM populations
-        "#
-    )
-        .fetch_all(&state.pool)
-        .await
-        .unwrap_or_default();
+) -> Result<impl IntoResponse, AppError> {
+    let pool = state.pool.clone();
+    let body = get_or_refresh_cached(&state.population_cache, state.cache_ttl, || async move {
+        let populations = sqlx::query_as!(
+            Population,
+            r#"
+            SELECT id, name, region
+            FROM populations
+            "#
+        )
+            .fetch_all(&pool)
+            .await?;
 
-    Json(populations)
+        Ok(serde_json::to_vec(&populations).unwrap_or_default())
+    })
+        .await?;
+
+    Ok(([(header::CONTENT_TYPE, "application/json")], body))
 }
 
 async fn get_all_flux(
     State(state): State<Arc<AppState>>,
-) -> Json<Vec<Flux>> {
+) -> Result<Json<Vec<Flux>>, AppError> {
     let flux = sqlx::query_as!(
         Flux,
         r#"
@@ -168,15 +486,14 @@ async fn get_all_flux(
         "#
     )
         .fetch_all(&state.pool)
-        .await
-        .unwrap_or_default();
+        .await?;
 
-    Json(flux)
+    Ok(Json(flux))
 }
 
 async fn get_all_geo_arg(
     State(state): State<Arc<AppState>>,
-) -> Json<Vec<GeoArg>> {
+) -> Result<Json<Vec<GeoArg>>, AppError> {
     let geo_args = sqlx::query_as!(
         GeoArg,
         r#"
@@ -185,10 +502,9 @@ async fn get_all_geo_arg(
         "#
     )
         .fetch_all(&state.pool)
-        .await
-        .unwrap_or_default();
+        .await?;
 
-    Json(geo_args)
+    Ok(Json(geo_args))
 }
 
 // Health check endpoint
@@ -198,18 +514,107 @@ async fn health_check() -> Json<serde_json::Value> {
         "timestamp": chrono::Utc::now().to_rfc3339()
     }))
 }
-// First, add these indexes to your database schema:
-/*
-CREATE INDEX IF NOT EXISTS idx_edges_child ON edges(child);
-CREATE INDEX IF NOT EXISTS idx_edges_parent ON edges(parent);
-CREATE INDEX IF NOT EXISTS idx_geo_arg_edge_id ON geo_arg(edge_id);
-CREATE INDEX IF NOT EXISTS idx_individuals_nodes ON individuals USING GIN(nodes);
-*/
+
+// Mapbox Vector Tile endpoints. Tiles are built entirely in Postgres: clip
+// each geometry to the tile envelope, attach the columns the frontend wants
+// as feature properties, and aggregate into a single MVT layer per table so
+// the client only ever pulls the features in view instead of the whole table.
+async fn get_hexagon_tile(
+    Path((z, x, y)): Path<(i32, i32, String)>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let y: i32 = y
+        .trim_end_matches(".pbf")
+        .parse()
+        .map_err(|_| AppError::BadRequest("tile y coordinate must be an integer".to_string()))?;
+    let tile = sqlx::query_scalar!(
+        r#"
+        WITH bounds AS (
+            SELECT ST_TileEnvelope($1, $2, $3) AS geom
+        ),
+        mvtgeom AS (
+            SELECT
+                ST_AsMVTGeom(
+                    ST_Transform(h.geom, 3857),
+                    bounds.geom,
+                    4096,
+                    64,
+                    true
+                ) AS geom,
+                h.state_id,
+                h.continent_id
+            FROM hexagons h, bounds
+            WHERE ST_Intersects(ST_Transform(h.geom, 3857), bounds.geom)
+        )
+        SELECT ST_AsMVT(mvtgeom.*, 'hexagons') AS "tile: Vec<u8>"
+        FROM mvtgeom
+        "#,
+        z,
+        x,
+        y
+    )
+        .fetch_one(&state.pool)
+        .await?;
+
+    // ST_AsMVT returns SQL NULL for a tile with no rows in it (a routine
+    // pan/zoom into an empty area), not an error — the response is just an
+    // empty tile body, not a 500.
+    Ok(([(header::CONTENT_TYPE, "application/vnd.mapbox-vector-tile")], tile.unwrap_or_default()))
+}
+
+async fn get_flux_tile(
+    Path((z, x, y)): Path<(i32, i32, String)>,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let y: i32 = y
+        .trim_end_matches(".pbf")
+        .parse()
+        .map_err(|_| AppError::BadRequest("tile y coordinate must be an integer".to_string()))?;
+    let tile = sqlx::query_scalar!(
+        r#"
+        WITH bounds AS (
+            SELECT ST_TileEnvelope($1, $2, $3) AS geom
+        ),
+        mvtgeom AS (
+            SELECT
+                ST_AsMVTGeom(
+                    ST_Transform(h.geom, 3857),
+                    bounds.geom,
+                    4096,
+                    64,
+                    true
+                ) AS geom,
+                h.state_id,
+                AVG(f.migration_rate) AS average_migration_rate
+            FROM hexagons h
+            JOIN flux f ON f.source_state_id = h.state_id
+            CROSS JOIN bounds
+            WHERE ST_Intersects(ST_Transform(h.geom, 3857), bounds.geom)
+            GROUP BY h.geom, h.state_id, bounds.geom
+        )
+        SELECT ST_AsMVT(mvtgeom.*, 'flux') AS "tile: Vec<u8>"
+        FROM mvtgeom
+        "#,
+        z,
+        x,
+        y
+    )
+        .fetch_one(&state.pool)
+        .await?;
+
+    // ST_AsMVT returns SQL NULL for a tile with no rows in it (a routine
+    // pan/zoom into an empty area), not an error — the response is just an
+    // empty tile body, not a 500.
+    Ok(([(header::CONTENT_TYPE, "application/vnd.mapbox-vector-tile")], tile.unwrap_or_default()))
+}
+// The edges/geo_arg/individuals indexes the queries below rely on (and the
+// rest of the schema) are applied by the embedded migrations in
+// `migrations/` at startup instead of being hand-maintained here.
 
 async fn get_origin_paths(
     Path(state_id): Path<i32>,
     State(state): State<Arc<AppState>>,
-) -> Json<Vec<GeoArgPath>> {
+) -> Result<Json<Vec<GeoArgPath>>, AppError> {
     // First, get a limited set of edges that involve our state_id
     let paths = sqlx::query_as!(
         GeoArg,
@@ -233,8 +638,7 @@ async fn get_origin_paths(
         state_id
     )
         .fetch_all(&state.pool)
-        .await
-        .unwrap_or_default();
+        .await?;
 
     let mut grouped_paths: Vec<GeoArgPath> = Vec::new();
     let mut current_edge_id: Option<i32> = None;
@@ -262,37 +666,63 @@ async fn get_origin_paths(
         });
     }
 
-    Json(grouped_paths)
+    Ok(Json(grouped_paths))
 }
 
 async fn get_average_flux(
     State(state): State<Arc<AppState>>,
-) -> Json<Vec<AverageFlux>> {
-    let average_flux = sqlx::query_as!(
-        AverageFlux,
-        r#"
-        SELECT
-            source_state_id,
-            target_state_id,
-            AVG(migration_rate) as "average_migration_rate!: f64"
-        FROM flux
-        GROUP BY source_state_id, target_state_id
-        HAVING AVG(migration_rate) > 0
-        ORDER BY source_state_id, target_state_id
-        "#
-    )
-        .fetch_all(&state.pool)
-        .await
-        .unwrap_or_default();
+) -> Result<impl IntoResponse, AppError> {
+    let pool = state.pool.clone();
+    let body = get_or_refresh_cached(&state.average_flux_cache, state.cache_ttl, || async move {
+        let average_flux = sqlx::query_as!(
+            AverageFlux,
+            r#"
+            SELECT
+                source_state_id,
+                target_state_id,
+                AVG(migration_rate) as "average_migration_rate!: f64"
+            FROM flux
+            GROUP BY source_state_id, target_state_id
+            HAVING AVG(migration_rate) > 0
+            ORDER BY source_state_id, target_state_id
+            "#
+        )
+            .fetch_all(&pool)
+            .await?;
+
+        Ok(serde_json::to_vec(&average_flux).unwrap_or_default())
+    })
+        .await?;
+
+    Ok(([(header::CONTENT_TYPE, "application/json")], body))
+}
+
+async fn invalidate_cache(State(state): State<Arc<AppState>>) -> Result<Json<serde_json::Value>, AppError> {
+    *state.hexagon_cache.write().await = None;
+    *state.population_cache.write().await = None;
+    *state.average_flux_cache.write().await = None;
 
-    Json(average_flux)
+    // The response caches above are just serialized query results and are
+    // safe to drop and recompute lazily; `flux_graph`/`hex_index` are
+    // in-memory structures built once at startup, so reloaded flux/hexagon
+    // data wouldn't otherwise affect routing or nearest-hex lookups until
+    // the server restarts.
+    let flux_graph = load_flux_graph(&state.pool).await?;
+    let hex_index = load_hex_index(&state.pool).await?;
+    *state.flux_graph.write().await = flux_graph;
+    *state.hex_index.write().await = hex_index;
+
+    Ok(Json(serde_json::json!({ "status": "invalidated" })))
 }
 
 // Add this new handler function to your existing code
-async fn get_individual_origin_paths(
-    Path(individual_id): Path<i32>,
-    State(state): State<Arc<AppState>>,
-) -> Json<Vec<GeoArgPath>> {
+// Runs the recursive node-tree traversal and groups the resulting geo_arg
+// entries by edge_id. Shared by the synchronous handler and the background
+// job worker so both paths compute identical results.
+async fn compute_individual_origin_paths(
+    pool: &sqlx::PgPool,
+    individual_id: i32,
+) -> Result<Vec<GeoArgPath>, sqlx::Error> {
     // First, recursively get all edges leading to the individual's nodes
     let edges = sqlx::query!(
         r#"
@@ -320,15 +750,14 @@ async fn get_individual_origin_paths(
         "#,
         individual_id
     )
-        .fetch_all(&state.pool)
-        .await
-        .unwrap_or_default();
+        .fetch_all(pool)
+        .await?;
 
     let edge_ids: Vec<i32> = edges.iter().map(|e| e.edge_id).collect();
 
     // If no edges found, return empty result
     if edge_ids.is_empty() {
-        return Json(Vec::new());
+        return Ok(Vec::new());
     }
 
     // Get all geo_arg entries for these edges
@@ -345,9 +774,8 @@ async fn get_individual_origin_paths(
         "#,
         &edge_ids
     )
-        .fetch_all(&state.pool)
-        .await
-        .unwrap_or_default();
+        .fetch_all(pool)
+        .await?;
 
     // Group the paths by edge_id
     let mut grouped_paths: Vec<GeoArgPath> = Vec::new();
@@ -377,7 +805,174 @@ async fn get_individual_origin_paths(
         });
     }
 
-    Json(grouped_paths)
+    Ok(grouped_paths)
+}
+
+async fn get_individual_origin_paths(
+    Path(individual_id): Path<i32>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<GeoArgPath>>, AppError> {
+    let grouped_paths = compute_individual_origin_paths(&state.pool, individual_id).await?;
+
+    if grouped_paths.is_empty() {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(Json(grouped_paths))
+}
+
+// Job-queue enums/handlers for precomputing origin-path results
+// asynchronously instead of recomputing the recursive CTE on every request.
+#[derive(sqlx::Type, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[sqlx(type_name = "job_status", rename_all = "lowercase")]
+enum JobStatus {
+    New,
+    Running,
+    Done,
+    Failed,
+}
+
+#[derive(Deserialize)]
+struct EnqueueOriginPathJob {
+    individual_id: i32,
+}
+
+#[derive(Serialize)]
+struct JobEnqueued {
+    id: sqlx::types::Uuid,
+}
+
+async fn enqueue_origin_path_job(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<EnqueueOriginPathJob>,
+) -> Result<Json<JobEnqueued>, AppError> {
+    let job = serde_json::json!({ "individual_id": payload.individual_id });
+
+    let row = sqlx::query!(
+        r#"
+        INSERT INTO job_queue (queue, job, status)
+        VALUES ('origin_paths', $1, 'new')
+        RETURNING id
+        "#,
+        job
+    )
+        .fetch_one(&state.pool)
+        .await?;
+
+    Ok(Json(JobEnqueued { id: row.id }))
+}
+
+#[derive(Serialize)]
+struct OriginPathJobStatus {
+    id: sqlx::types::Uuid,
+    status: JobStatus,
+    result: Option<JsonValue>,
+}
+
+async fn get_origin_path_job(
+    Path(job_id): Path<sqlx::types::Uuid>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<OriginPathJobStatus>, AppError> {
+    let job = sqlx::query_as!(
+        OriginPathJobStatus,
+        r#"
+        SELECT id, status as "status!: JobStatus", result
+        FROM job_queue
+        WHERE id = $1 AND queue = 'origin_paths'
+        "#,
+        job_id
+    )
+        .fetch_optional(&state.pool)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    Ok(Json(job))
+}
+
+// Background worker: claims one `new` job at a time with SKIP LOCKED,
+// computes the origin-path grouping, and writes the result back. A stale
+// `running` job (crashed worker) is reset to `new` so another tick can pick
+// it back up.
+async fn run_origin_path_worker(pool: sqlx::PgPool) {
+    let mut ticker = tokio::time::interval(std::time::Duration::from_secs(2));
+
+    loop {
+        ticker.tick().await;
+
+        sqlx::query!(
+            r#"
+            UPDATE job_queue
+            SET status = 'new'
+            WHERE queue = 'origin_paths'
+              AND status = 'running'
+              AND heartbeat < now() - interval '60 seconds'
+            "#
+        )
+            .execute(&pool)
+            .await
+            .ok();
+
+        let claimed = sqlx::query!(
+            r#"
+            UPDATE job_queue
+            SET status = 'running', heartbeat = now()
+            WHERE id = (
+                SELECT id FROM job_queue
+                WHERE queue = 'origin_paths' AND status = 'new'
+                ORDER BY id
+                FOR UPDATE SKIP LOCKED
+                LIMIT 1
+            )
+            RETURNING id, job
+            "#
+        )
+            .fetch_optional(&pool)
+            .await
+            .unwrap_or_default();
+
+        let Some(row) = claimed else { continue };
+
+        let individual_id = match row.job.get("individual_id").and_then(|v| v.as_i64()) {
+            Some(id) => id as i32,
+            None => {
+                sqlx::query!(
+                    "UPDATE job_queue SET status = 'failed', heartbeat = now() WHERE id = $1",
+                    row.id
+                )
+                    .execute(&pool)
+                    .await
+                    .ok();
+                continue;
+            }
+        };
+
+        match compute_individual_origin_paths(&pool, individual_id).await {
+            Ok(result) => {
+                let result = serde_json::to_value(&result).unwrap_or_default();
+                sqlx::query!(
+                    r#"
+                    UPDATE job_queue
+                    SET status = 'done', result = $2, heartbeat = now()
+                    WHERE id = $1
+                    "#,
+                    row.id,
+                    result
+                )
+                    .execute(&pool)
+                    .await
+                    .ok();
+            }
+            Err(_) => {
+                sqlx::query!(
+                    "UPDATE job_queue SET status = 'failed', heartbeat = now() WHERE id = $1",
+                    row.id
+                )
+                    .execute(&pool)
+                    .await
+                    .ok();
+            }
+        }
+    }
 }
 
 #[tokio::main]
@@ -398,8 +993,50 @@ async fn main() {
         .await
         .expect("Failed to create connection pool");
 
+    // Bring the schema up to date before serving anything.
+    MIGRATOR.run(&pool).await.expect("Failed to run database migrations");
+
+    if std::env::args().any(|arg| arg == "--migrate-only") {
+        println!("Migrations applied successfully, exiting due to --migrate-only");
+        return;
+    }
+
+    // Load the averaged flux graph and hexagon center index into memory so
+    // migration-route requests don't hit Postgres on every call.
+    let flux_graph = load_flux_graph(&pool)
+        .await
+        .expect("Failed to load flux graph");
+    let hex_index = load_hex_index(&pool)
+        .await
+        .expect("Failed to load hexagon index");
+
+    // Spawn the background worker that drains the origin-paths job queue.
+    tokio::spawn(run_origin_path_worker(pool.clone()));
+
+    // How long a cached full-table response is served before the next
+    // request triggers a refresh.
+    let cache_ttl = std::env::var("CACHE_TTL_SECONDS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(std::time::Duration::from_secs)
+        .unwrap_or(std::time::Duration::from_secs(60));
+
     // Create shared state
-    let state = Arc::new(AppState { pool });
+    let state = Arc::new(AppState {
+        pool,
+        flux_graph: Arc::new(RwLock::new(flux_graph)),
+        hex_index: Arc::new(RwLock::new(hex_index)),
+        cache_ttl,
+        hexagon_cache: Arc::new(RwLock::new(None)),
+        population_cache: Arc::new(RwLock::new(None)),
+        average_flux_cache: Arc::new(RwLock::new(None)),
+    });
+
+    let compression_level = std::env::var("COMPRESSION_LEVEL")
+        .ok()
+        .and_then(|s| s.parse::<i32>().ok())
+        .map(CompressionLevel::Precise)
+        .unwrap_or(CompressionLevel::Default);
 
     // Build our application with routes
     let app = Router::new()
@@ -412,6 +1049,13 @@ async fn main() {
         .route("/api/origin-paths/:state_id", get(get_origin_paths))
         .route("/health", get(health_check))
         .route("/api/individual-origin-paths/:individual_id", get(get_individual_origin_paths))
+        .route("/api/tiles/hexagons/:z/:x/:y", get(get_hexagon_tile))
+        .route("/api/tiles/flux/:z/:x/:y", get(get_flux_tile))
+        .route("/api/migration-route/:source_state_id/:target_state_id", get(get_migration_route))
+        .route("/api/origin-paths/jobs", post(enqueue_origin_path_job))
+        .route("/api/origin-paths/jobs/:id", get(get_origin_path_job))
+        .route("/api/cache/invalidate", post(invalidate_cache))
+        .layer(CompressionLayer::new().quality(compression_level))
         .layer(CorsLayer::permissive())
         .with_state(state);
 